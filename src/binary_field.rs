@@ -3,9 +3,13 @@ use rand::prelude::Distribution;
 use rand::Rng;
 use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
-use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-pub trait BinaryFieldConfig: Clone + Debug + PartialEq + Eq {
+/// `Send + Sync + 'static` let `BinaryField<F>`/`Ring<F>` values cross into
+/// rayon's thread pool, which the `parallel` feature's `into_par_iter()` and
+/// `rayon::join` calls in [`crate::tower`] and [`crate::polynomial`] both
+/// require.
+pub trait BinaryFieldConfig: Clone + Copy + Debug + PartialEq + Eq + Send + Sync + 'static {
     const N: usize;
 
     fn get_poly<'a>() -> &'a [bool];
@@ -13,19 +17,61 @@ pub trait BinaryFieldConfig: Clone + Debug + PartialEq + Eq {
     fn get_imag_unit<'a>() -> &'a [bool];
 }
 
-#[derive(Clone, PartialEq, Eq)]
+/// Number of `u64` limbs needed to pack `n` coefficient bits.
+const fn limbs_for(n: usize) -> usize {
+    n.div_ceil(64)
+}
+
+fn get_bit_of(data: &[u64], i: usize) -> bool {
+    (data[i / 64] >> (i % 64)) & 1 == 1
+}
+
+fn set_bit_of(data: &mut [u64], i: usize, value: bool) {
+    let mask = 1u64 << (i % 64);
+    if value {
+        data[i / 64] |= mask;
+    } else {
+        data[i / 64] &= !mask;
+    }
+}
+
+fn flip_bit_of(data: &mut [u64], i: usize) {
+    data[i / 64] ^= 1u64 << (i % 64);
+}
+
+/// XORs `src << shift` (as a bit string) into `out`, word by word.
+fn shl_xor_into(src: &[u64], shift: usize, out: &mut [u64]) {
+    let word_shift = shift / 64;
+    let bit_shift = shift % 64;
+    for i in (0..src.len()).rev() {
+        let idx = i + word_shift;
+        if idx >= out.len() {
+            continue;
+        }
+        out[idx] ^= src[i] << bit_shift;
+        if bit_shift > 0 && idx + 1 < out.len() {
+            out[idx + 1] ^= src[i] >> (64 - bit_shift);
+        }
+    }
+}
+
+/// A single coefficient in `GF(2^N)`, packed into two `u64` limbs (bit `i`
+/// of the field element lives in limb `i / 64`, bit `i % 64`) instead of one
+/// `bool` per bit. `N` is bounded by 128 so the element stays `Copy`, which
+/// the `ff::Field` impl in [`crate::ff_impl`] requires.
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct BinaryField<F: BinaryFieldConfig> {
-    pub data: Vec<bool>,
+    pub data: [u64; 2],
     pub marker: PhantomData<F>,
 }
 
 impl<F: BinaryFieldConfig> Debug for BinaryField<F> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        self.data.fmt(f)
+        self.to_bits().fmt(f)
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AESPoly;
 impl BinaryFieldConfig for AESPoly {
     const N: usize = 8;
@@ -39,7 +85,7 @@ impl BinaryFieldConfig for AESPoly {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct F2;
 impl BinaryFieldConfig for F2 {
     const N: usize = 1;
@@ -54,82 +100,119 @@ impl BinaryFieldConfig for F2 {
 }
 
 impl<F: BinaryFieldConfig> BinaryField<F> {
+    const LIMBS: usize = limbs_for(F::N);
+
     pub fn zero() -> Self {
         Self::default()
     }
 
     pub fn one() -> Self {
         let mut res = Self::default();
-        res.data[0] = true;
+        res.set_bit(0, true);
+        res
+    }
+
+    fn get_bit(&self, i: usize) -> bool {
+        get_bit_of(&self.data, i)
+    }
+
+    fn set_bit(&mut self, i: usize, value: bool) {
+        set_bit_of(&mut self.data, i, value);
+    }
+
+    /// Packs a length-`N` slice of coefficient bits into limbs.
+    pub fn from_bits(bits: &[bool]) -> Self {
+        assert_eq!(bits.len(), F::N);
+        let mut res = Self::default();
+        for (i, bit) in bits.iter().enumerate() {
+            if *bit {
+                res.set_bit(i, true);
+            }
+        }
         res
     }
+
+    /// Unpacks the field element into a length-`N` vector of coefficient bits.
+    pub fn to_bits(&self) -> Vec<bool> {
+        (0..F::N).map(|i| self.get_bit(i)).collect()
+    }
+
+    /// Canonical little-endian byte encoding, `ceil(N/8)` bytes long.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; F::N.div_ceil(8)];
+        for (i, bit) in self.to_bits().into_iter().enumerate() {
+            if bit {
+                bytes[i / 8] |= 1 << (i % 8);
+            }
+        }
+        bytes
+    }
+
+    /// Inverse of [`Self::to_bytes`]. Returns `None` if `bytes` is not
+    /// exactly `ceil(N/8)` bytes long.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != F::N.div_ceil(8) {
+            return None;
+        }
+        let bits: Vec<bool> = (0..F::N)
+            .map(|i| (bytes[i / 8] >> (i % 8)) & 1 == 1)
+            .collect();
+        Some(Self::from_bits(&bits))
+    }
 }
 
 impl<F: BinaryFieldConfig> Default for BinaryField<F> {
     fn default() -> Self {
+        assert!(F::N <= 128);
         Self {
-            data: vec![false; F::N],
+            data: [0u64; 2],
             marker: PhantomData,
         }
     }
 }
 
 impl<F: BinaryFieldConfig> From<u8> for BinaryField<F> {
-    fn from(mut value: u8) -> Self {
+    fn from(value: u8) -> Self {
         assert!(F::N >= 8);
         let mut res = BinaryField::<F>::default();
-        for i in 0..8 {
-            res.data[i] = value & 1 == 1;
-            value >>= 1;
-        }
+        res.data[0] |= value as u64;
         res
     }
 }
 
 impl<F: BinaryFieldConfig> From<u16> for BinaryField<F> {
-    fn from(mut value: u16) -> Self {
+    fn from(value: u16) -> Self {
         assert!(F::N >= 16);
         let mut res = BinaryField::<F>::default();
-        for i in 0..16 {
-            res.data[i] = value & 1 == 1;
-            value >>= 1;
-        }
+        res.data[0] |= value as u64;
         res
     }
 }
 
 impl<F: BinaryFieldConfig> From<u32> for BinaryField<F> {
-    fn from(mut value: u32) -> Self {
+    fn from(value: u32) -> Self {
         assert!(F::N >= 32);
         let mut res = BinaryField::<F>::default();
-        for i in 0..32 {
-            res.data[i] = value & 1 == 1;
-            value >>= 1;
-        }
+        res.data[0] |= value as u64;
         res
     }
 }
 
 impl<F: BinaryFieldConfig> From<u64> for BinaryField<F> {
-    fn from(mut value: u64) -> Self {
+    fn from(value: u64) -> Self {
         assert!(F::N >= 64);
         let mut res = BinaryField::<F>::default();
-        for i in 0..64 {
-            res.data[i] = value & 1 == 1;
-            value >>= 1;
-        }
+        res.data[0] |= value;
         res
     }
 }
 
 impl<F: BinaryFieldConfig> From<u128> for BinaryField<F> {
-    fn from(mut value: u128) -> Self {
+    fn from(value: u128) -> Self {
         assert!(F::N >= 128);
         let mut res = BinaryField::<F>::default();
-        for i in 0..128 {
-            res.data[i] = value & 1 == 1;
-            value >>= 1;
-        }
+        res.data[0] |= value as u64;
+        res.data[1] |= (value >> 64) as u64;
         res
     }
 }
@@ -137,10 +220,12 @@ impl<F: BinaryFieldConfig> From<u128> for BinaryField<F> {
 impl<F: BinaryFieldConfig> Add<&BinaryField<F>> for &BinaryField<F> {
     type Output = BinaryField<F>;
 
+    // Addition in GF(2^N) is XOR, not `+`; this isn't a mixed-up operator.
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn add(self, rhs: &BinaryField<F>) -> BinaryField<F> {
-        let mut res = BinaryField::<F>::default();
-        for i in 0..F::N {
-            res.data[i] = self.data[i] ^ rhs.data[i];
+        let mut res = *self;
+        for i in 0..BinaryField::<F>::LIMBS {
+            res.data[i] ^= rhs.data[i];
         }
         res
     }
@@ -154,14 +239,30 @@ impl<F: BinaryFieldConfig> Add<BinaryField<F>> for BinaryField<F> {
     }
 }
 
+impl<F: BinaryFieldConfig> Add<&BinaryField<F>> for BinaryField<F> {
+    type Output = BinaryField<F>;
+
+    fn add(self, rhs: &BinaryField<F>) -> Self::Output {
+        self + *rhs
+    }
+}
+
 impl<F: BinaryFieldConfig> AddAssign<&BinaryField<F>> for BinaryField<F> {
+    // Addition in GF(2^N) is XOR, not `+`; this isn't a mixed-up operator.
+    #[allow(clippy::suspicious_op_assign_impl)]
     fn add_assign(&mut self, rhs: &BinaryField<F>) {
-        for i in 0..F::N {
+        for i in 0..Self::LIMBS {
             self.data[i] ^= rhs.data[i];
         }
     }
 }
 
+impl<F: BinaryFieldConfig> AddAssign<BinaryField<F>> for BinaryField<F> {
+    fn add_assign(&mut self, rhs: BinaryField<F>) {
+        self.add_assign(&rhs);
+    }
+}
+
 impl<F: BinaryFieldConfig> Sub<&BinaryField<F>> for &BinaryField<F> {
     type Output = BinaryField<F>;
 
@@ -170,38 +271,91 @@ impl<F: BinaryFieldConfig> Sub<&BinaryField<F>> for &BinaryField<F> {
     }
 }
 
+impl<F: BinaryFieldConfig> Sub<BinaryField<F>> for BinaryField<F> {
+    type Output = BinaryField<F>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<F: BinaryFieldConfig> Sub<&BinaryField<F>> for BinaryField<F> {
+    type Output = BinaryField<F>;
+
+    fn sub(self, rhs: &BinaryField<F>) -> Self::Output {
+        self - *rhs
+    }
+}
+
 impl<F: BinaryFieldConfig> SubAssign<&BinaryField<F>> for BinaryField<F> {
     fn sub_assign(&mut self, rhs: &BinaryField<F>) {
         self.add_assign(rhs)
     }
 }
 
+impl<F: BinaryFieldConfig> SubAssign<BinaryField<F>> for BinaryField<F> {
+    fn sub_assign(&mut self, rhs: BinaryField<F>) {
+        self.add_assign(&rhs)
+    }
+}
+
+impl<F: BinaryFieldConfig> Neg for BinaryField<F> {
+    type Output = BinaryField<F>;
+
+    /// Characteristic-2 fields are their own additive inverse.
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
 impl<F: BinaryFieldConfig> Mul<&BinaryField<F>> for &BinaryField<F> {
     type Output = BinaryField<F>;
 
     fn mul(self, rhs: &BinaryField<F>) -> Self::Output {
-        let mut temp = vec![false; 2 * F::N - 1];
+        // Schoolbook carry-less multiplication: shift-and-XOR `rhs` into a
+        // wide limb buffer for every set bit of `self`, instead of looping
+        // bit-by-bit over both operands. `2*N - 1 <= 255` bits fits in 4 limbs.
+        let mut temp = [0u64; 4];
         for i in 0..F::N {
-            for j in 0..F::N {
-                temp[i + j] ^= self.data[i] & rhs.data[j];
+            if self.get_bit(i) {
+                shl_xor_into(&rhs.data, i, &mut temp);
             }
         }
 
         let poly = F::get_poly();
 
         for i in (F::N..(2 * F::N - 1)).rev() {
-            if temp[i] {
-                temp[i] = false;
-                for j in 0..F::N {
-                    temp[i - 1 - j] ^= poly[j];
+            if get_bit_of(&temp, i) {
+                set_bit_of(&mut temp, i, false);
+                for (j, set) in poly.iter().enumerate() {
+                    if *set {
+                        flip_bit_of(&mut temp, i - 1 - j);
+                    }
                 }
             }
         }
 
-        BinaryField::<F> {
-            data: temp[0..F::N].try_into().unwrap(),
-            marker: PhantomData,
+        let mut res = BinaryField::<F>::default();
+        for i in 0..F::N {
+            res.set_bit(i, get_bit_of(&temp, i));
         }
+        res
+    }
+}
+
+impl<F: BinaryFieldConfig> Mul<BinaryField<F>> for BinaryField<F> {
+    type Output = BinaryField<F>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<F: BinaryFieldConfig> Mul<&BinaryField<F>> for BinaryField<F> {
+    type Output = BinaryField<F>;
+
+    fn mul(self, rhs: &BinaryField<F>) -> Self::Output {
+        self * *rhs
     }
 }
 
@@ -211,26 +365,102 @@ impl<F: BinaryFieldConfig> MulAssign<&BinaryField<F>> for BinaryField<F> {
     }
 }
 
+impl<F: BinaryFieldConfig> MulAssign<BinaryField<F>> for BinaryField<F> {
+    fn mul_assign(&mut self, rhs: BinaryField<F>) {
+        self.mul_assign(&rhs);
+    }
+}
+
 impl<F: BinaryFieldConfig> BinaryField<F> {
-    pub fn mul_by_imag_unit(&self) -> BinaryField<F> {
-        let imag_unit = BinaryField::<F> {
-            data: F::get_imag_unit().to_vec(),
-            marker: PhantomData,
+    /// Computes `self^-1` by raising `self` to `2^N - 2`, which is the
+    /// multiplicative inverse in `GF(2^N)` by Fermat's little theorem.
+    /// Returns `None` if `self` is zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if *self == Self::zero() {
+            return None;
+        }
+
+        let exponent: u128 = if F::N == 128 {
+            u128::MAX - 1
+        } else {
+            (1u128 << F::N) - 2
         };
+
+        let mut result = Self::one();
+        let mut base = *self;
+        for i in 0..F::N {
+            if (exponent >> i) & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+        }
+        Some(result)
+    }
+
+    /// Inverts every element of `values` using Montgomery's trick: one
+    /// inversion plus `3*n` multiplications instead of `n` inversions.
+    /// Zero entries are left as zero rather than poisoning the batch.
+    pub fn batch_inverse(values: &[Self]) -> Vec<Self> {
+        let mut prefix = Vec::with_capacity(values.len() + 1);
+        prefix.push(Self::one());
+        for v in values {
+            let last = prefix.last().unwrap();
+            let next = if *v == Self::zero() {
+                *last
+            } else {
+                last * v
+            };
+            prefix.push(next);
+        }
+
+        let mut t = prefix[values.len()]
+            .inverse()
+            .expect("product of one() and nonzero factors is never zero");
+
+        let mut result = vec![Self::zero(); values.len()];
+        for i in (0..values.len()).rev() {
+            if values[i] == Self::zero() {
+                continue;
+            }
+            result[i] = t * prefix[i];
+            t *= values[i];
+        }
+        result
+    }
+}
+
+impl<F: BinaryFieldConfig> Div<&BinaryField<F>> for &BinaryField<F> {
+    type Output = BinaryField<F>;
+
+    // Division is multiplication by the inverse; this isn't a mixed-up operator.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: &BinaryField<F>) -> Self::Output {
+        self * &rhs.inverse().expect("division by zero")
+    }
+}
+
+impl<F: BinaryFieldConfig> DivAssign<&BinaryField<F>> for BinaryField<F> {
+    fn div_assign(&mut self, rhs: &BinaryField<F>) {
+        *self = (self as &Self).div(rhs);
+    }
+}
+
+impl<F: BinaryFieldConfig> BinaryField<F> {
+    pub fn mul_by_imag_unit(&self) -> BinaryField<F> {
+        let imag_unit = BinaryField::<F>::from_bits(F::get_imag_unit());
         self * &imag_unit
     }
 }
 
 impl<F: BinaryFieldConfig> Distribution<BinaryField<F>> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BinaryField<F> {
-        let mut data = vec![];
-        for _ in 0..F::N {
-            data.push(rng.gen());
-        }
-        BinaryField::<F> {
-            data,
-            marker: PhantomData,
+        let mut res = BinaryField::<F>::default();
+        for i in 0..F::N {
+            if rng.gen() {
+                res.set_bit(i, true);
+            }
         }
+        res
     }
 }
 
@@ -279,4 +509,69 @@ mod test {
             assert_eq!(result, expected_bf);
         }
     }
+
+    #[test]
+    fn test_aes_field_inverse() {
+        let mut prng = ChaCha20Rng::seed_from_u64(0);
+
+        assert!(BinaryField::<AESPoly>::zero().inverse().is_none());
+
+        for _ in 0..100 {
+            let a: u8 = loop {
+                let candidate = prng.gen();
+                if candidate != 0 {
+                    break candidate;
+                }
+            };
+
+            let a_bf = BinaryField::<AESPoly>::from(a);
+            let a_inv = a_bf.inverse().unwrap();
+
+            assert_eq!(a_bf.mul(&a_inv), BinaryField::<AESPoly>::one());
+        }
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let mut prng = ChaCha20Rng::seed_from_u64(3);
+
+        let mut values: Vec<BinaryField<AESPoly>> =
+            (0..20).map(|_| BinaryField::<AESPoly>::from(prng.gen::<u8>())).collect();
+        values[5] = BinaryField::<AESPoly>::zero();
+
+        let inverses = BinaryField::<AESPoly>::batch_inverse(&values);
+
+        for (v, inv) in values.iter().zip(inverses.iter()) {
+            if *v == BinaryField::<AESPoly>::zero() {
+                assert_eq!(*inv, BinaryField::<AESPoly>::zero());
+            } else {
+                assert_eq!(v.mul(inv), BinaryField::<AESPoly>::one());
+            }
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_bytes() {
+        let mut prng = ChaCha20Rng::seed_from_u64(2);
+
+        for _ in 0..100 {
+            let a: u8 = prng.gen();
+            let a_bf = BinaryField::<AESPoly>::from(a);
+            let bytes = a_bf.to_bytes();
+            assert_eq!(bytes, vec![a]);
+            assert_eq!(BinaryField::<AESPoly>::from_bytes(&bytes).unwrap(), a_bf);
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_bits() {
+        let mut prng = ChaCha20Rng::seed_from_u64(1);
+
+        for _ in 0..100 {
+            let a: u8 = prng.gen();
+            let a_bf = BinaryField::<AESPoly>::from(a);
+            let bits = a_bf.to_bits();
+            assert_eq!(BinaryField::<AESPoly>::from_bits(&bits), a_bf);
+        }
+    }
 }
@@ -0,0 +1,205 @@
+//! `ff::Field` trait impls so `BinaryField<F>` can be used directly inside
+//! `ff`/`group`-based circuit and gadget frameworks (halo2, bellman, ...).
+//!
+//! `Ring<F>` is not given a `ff::Field` impl: its length `2^k` is a runtime
+//! parameter rather than a type-level constant, so it cannot supply the
+//! trait's `ZERO`/`ONE` associated constants for a single concrete type. It
+//! gets `zero`/`one`/`square`/`double`/`random`/`pow_vartime` as inherent
+//! methods instead (inversion is `Ring::inverse`, returning `Option`), so
+//! callers that don't need to be generic over `ff::Field` can still use
+//! them. `Ring` has no square-root method.
+
+use crate::binary_field::{BinaryField, BinaryFieldConfig};
+use crate::tower::Ring;
+use ff::Field;
+use rand::{Rng, RngCore};
+use std::iter::{Product, Sum};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+impl<F: BinaryFieldConfig> ConstantTimeEq for BinaryField<F> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.data[0].ct_eq(&other.data[0]) & self.data[1].ct_eq(&other.data[1])
+    }
+}
+
+impl<F: BinaryFieldConfig> ConditionallySelectable for BinaryField<F> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            data: [
+                u64::conditional_select(&a.data[0], &b.data[0], choice),
+                u64::conditional_select(&a.data[1], &b.data[1], choice),
+            ],
+            marker: a.marker,
+        }
+    }
+}
+
+impl<F: BinaryFieldConfig> Sum for BinaryField<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a, F: BinaryFieldConfig> Sum<&'a BinaryField<F>> for BinaryField<F> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<F: BinaryFieldConfig> Product for BinaryField<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<'a, F: BinaryFieldConfig> Product<&'a BinaryField<F>> for BinaryField<F> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, x| acc * x)
+    }
+}
+
+impl<F: BinaryFieldConfig> Field for BinaryField<F> {
+    const ZERO: Self = Self {
+        data: [0, 0],
+        marker: std::marker::PhantomData,
+    };
+
+    const ONE: Self = Self {
+        data: [1, 0],
+        marker: std::marker::PhantomData,
+    };
+
+    fn random(mut rng: impl RngCore) -> Self {
+        rng.gen()
+    }
+
+    fn square(&self) -> Self {
+        self * self
+    }
+
+    fn double(&self) -> Self {
+        // Characteristic 2: `a + a == 0`.
+        Self::ZERO
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        match self.inverse() {
+            Some(inv) => CtOption::new(inv, Choice::from(1)),
+            None => CtOption::new(Self::ZERO, Choice::from(0)),
+        }
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        // The Frobenius map `x -> x^2` is a field automorphism in
+        // characteristic 2, so every element of `GF(2^N)` has a unique
+        // square root, `x^(2^(N-1))`, and `num/div` is always a square when
+        // `div` is nonzero. `num == 0` must report success (with a 0 root)
+        // regardless of `div`, per the `ff::Field` contract, so that check
+        // comes first.
+        if *num == Self::ZERO {
+            return (Choice::from(1), Self::ZERO);
+        }
+        if *div == Self::ZERO {
+            return (Choice::from(0), Self::ZERO);
+        }
+        let ratio = num / div;
+        let mut root = ratio;
+        for _ in 0..F::N - 1 {
+            root = root.square();
+        }
+        (Choice::from(1), root)
+    }
+
+    fn pow_vartime<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut res = Self::ONE;
+        for e in exp.as_ref().iter().rev() {
+            for i in (0..64).rev() {
+                res = res.square();
+                if (e >> i) & 1 == 1 {
+                    res *= self;
+                }
+            }
+        }
+        res
+    }
+}
+
+impl<F: BinaryFieldConfig> Ring<F> {
+    /// The additive identity of length `len`. Unlike `BinaryField::ZERO`,
+    /// `Ring`'s length is a runtime parameter, so this takes one explicitly.
+    pub fn zero(len: usize) -> Self {
+        Ring {
+            elements: vec![BinaryField::<F>::ZERO; len],
+        }
+    }
+
+    /// The multiplicative identity of length `len`.
+    pub fn one(len: usize) -> Self {
+        let mut elements = vec![BinaryField::<F>::ZERO; len];
+        elements[0] = BinaryField::<F>::ONE;
+        Ring { elements }
+    }
+
+    pub fn square(&self) -> Self {
+        self * self
+    }
+
+    pub fn double(&self) -> Self {
+        Self::zero(self.get_len())
+    }
+
+    pub fn random<R: Rng + ?Sized>(len: usize, rng: &mut R) -> Self {
+        Ring {
+            elements: (0..len).map(|_| rng.gen()).collect(),
+        }
+    }
+
+    pub fn pow_vartime<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut res = Self::one(self.get_len());
+        for e in exp.as_ref().iter().rev() {
+            for i in (0..64).rev() {
+                res = res.square();
+                if (e >> i) & 1 == 1 {
+                    res = &res * self;
+                }
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::binary_field::{AESPoly, BinaryField};
+    use ff::Field;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use subtle::ConstantTimeEq;
+
+    #[test]
+    fn test_field_invert_and_square() {
+        let mut prng = ChaCha20Rng::seed_from_u64(0);
+
+        assert!(bool::from(BinaryField::<AESPoly>::ZERO.invert().is_none()));
+        assert_eq!(
+            BinaryField::<AESPoly>::ONE.square(),
+            BinaryField::<AESPoly>::ONE
+        );
+        assert_eq!(
+            BinaryField::<AESPoly>::ZERO.double(),
+            BinaryField::<AESPoly>::ZERO
+        );
+
+        for _ in 0..100 {
+            let a = BinaryField::<AESPoly>::random(&mut prng);
+            if bool::from(a.ct_eq(&BinaryField::<AESPoly>::ZERO)) {
+                continue;
+            }
+            let a_inv = a.invert().unwrap();
+            assert_eq!(a * a_inv, BinaryField::<AESPoly>::ONE);
+
+            let sqrt = a.square().sqrt().unwrap();
+            assert_eq!(sqrt, a);
+        }
+    }
+}
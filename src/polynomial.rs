@@ -14,16 +14,39 @@ impl<P: BinaryFieldConfig> Polynomial<P> {
         let dim = (self.evaluations.len() as u32).ilog2() as usize;
         assert_eq!(dim, x.len());
 
-        let mut poly = self.evaluations.clone();
+        // Each round folds `cur` into a fresh, half-sized buffer rather than
+        // overwriting `cur` in place, so the per-pair folds below have no
+        // dependency on each other and can run on the rayon pool.
+        let mut cur = self.evaluations.clone();
         for i in 1..dim + 1 {
             let r = &x[i - 1];
-            for b in 0..(1 << (dim - i)) {
-                let left = &poly[b << 1];
-                let right = &poly[(b << 1) + 1];
-                poly[b] = left + &(r * &(right - left));
-            }
+            let half = 1usize << (dim - i);
+
+            #[cfg(feature = "parallel")]
+            let next: Vec<Ring<P>> = {
+                use rayon::prelude::*;
+                (0..half)
+                    .into_par_iter()
+                    .map(|b| {
+                        let left = &cur[b << 1];
+                        let right = &cur[(b << 1) + 1];
+                        left + &(r * &(right - left))
+                    })
+                    .collect()
+            };
+
+            #[cfg(not(feature = "parallel"))]
+            let next: Vec<Ring<P>> = (0..half)
+                .map(|b| {
+                    let left = &cur[b << 1];
+                    let right = &cur[(b << 1) + 1];
+                    left + &(r * &(right - left))
+                })
+                .collect();
+
+            cur = next;
         }
-        poly[0].clone()
+        cur[0].clone()
     }
 }
 
@@ -49,12 +72,12 @@ mod test {
         let r1 = Ring::<AESPoly>::random(16, &mut prng);
         let r2 = Ring::<AESPoly>::random(16, &mut prng);
 
-        let one_minus_r1 = &Ring::<AESPoly>::one() - &r1;
-        let one_minus_r2 = &Ring::<AESPoly>::one() - &r2;
+        let one_minus_r1 = &Ring::<AESPoly>::one(16) - &r1;
+        let one_minus_r2 = &Ring::<AESPoly>::one(16) - &r2;
 
         let res = polynomial.evaluate(&[r1.clone(), r2.clone()]);
 
-        let mut expected = Ring::<AESPoly>::zero();
+        let mut expected = Ring::<AESPoly>::zero(16);
         expected += &(&(&one_minus_r1 * &one_minus_r2) * &polynomial.evaluations[0]);
         expected += &(&(&r1 * &one_minus_r2) * &polynomial.evaluations[1]);
         expected += &(&(&one_minus_r1 * &r2) * &polynomial.evaluations[2]);
@@ -1,6 +1,5 @@
 use crate::binary_field::{BinaryField, BinaryFieldConfig};
-use std::marker::PhantomData;
-use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Ring<F: BinaryFieldConfig> {
@@ -28,11 +27,19 @@ impl<F: BinaryFieldConfig> Ring<F> {
 impl<F: BinaryFieldConfig> Add<&Ring<F>> for &Ring<F> {
     type Output = Ring<F>;
 
+    /// Mismatched lengths are treated as a subring embedding: the shorter
+    /// operand is implicitly zero-extended up to the longer operand's
+    /// length (the same embedding [`Mul`] uses), so the result always has
+    /// the longer of the two lengths.
     fn add(self, rhs: &Ring<F>) -> Self::Output {
-        let mut res = self.elements.clone();
-        res.reserve(rhs.elements.len());
-        for i in 0..rhs.elements.len() {
-            res[i] += &rhs.elements[i];
+        let (long, short) = if self.get_len() >= rhs.get_len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut res = long.elements.clone();
+        for (r, short_elem) in res.iter_mut().zip(short.elements.iter()) {
+            *r += short_elem;
         }
         Ring { elements: res }
     }
@@ -61,27 +68,24 @@ impl<F: BinaryFieldConfig> SubAssign<&Ring<F>> for Ring<F> {
 impl<F: BinaryFieldConfig> Mul<&Ring<F>> for &Ring<F> {
     type Output = Ring<F>;
 
+    /// Mismatched lengths are treated as a subring embedding: the tower
+    /// construction in [`recursive_mul`] splits a length-`L` element into
+    /// `(low, high)` halves of length `L/2` each, so a shorter element
+    /// embeds into a longer ring by zero-extending it (the "high" parts of
+    /// every level down to its own length are zero) rather than by being
+    /// broadcast across independent chunks of the longer operand.
     fn mul(self, rhs: &Ring<F>) -> Self::Output {
         if self.get_len() != rhs.get_len() {
-            let mut long = self;
-            let mut short = rhs;
-            if long.get_len() < short.get_len() {
-                std::mem::swap(&mut long, &mut short);
-            }
+            let (long, short) = if self.get_len() > rhs.get_len() {
+                (self, rhs)
+            } else {
+                (rhs, self)
+            };
 
-            let long_len = long.get_len();
-            let short_len = short.get_len();
-
-            let k = long_len / short_len;
-            let mut res = vec![];
-
-            for i in 0..k {
-                let chunk_result = &Ring::<F> {
-                    elements: long.elements[(short_len * i)..(short_len * (i + 1))].to_vec(),
-                } * short;
-                res.extend(chunk_result.elements);
-            }
+            let mut embedded = short.elements.clone();
+            embedded.resize(long.get_len(), BinaryField::<F>::zero());
 
+            let res = recursive_mul(&long.elements, &embedded);
             Ring { elements: res }
         } else {
             let res = recursive_mul(&self.elements, &rhs.elements);
@@ -96,6 +100,102 @@ impl<F: BinaryFieldConfig> MulAssign<&Ring<F>> for Ring<F> {
     }
 }
 
+impl<F: BinaryFieldConfig> Ring<F> {
+    /// Computes `self^-1` by recursing on the tower of quadratic extensions,
+    /// reducing to a norm in the half-length subring at each level until the
+    /// base case is a `BinaryField`. Returns `None` if `self` is zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.get_len() == 1 {
+            let inv = self.elements[0].inverse()?;
+            return Some(Ring {
+                elements: vec![inv],
+            });
+        }
+
+        let half_len = self.get_len() / 2;
+        let a_lo = self.elements[..half_len].to_vec();
+        let a_hi = self.elements[half_len..].to_vec();
+
+        // a = a_lo + a_hi*X, where `recursive_mul` gives X^2 = U*X + 1 and U
+        // is the subring's imaginary unit, so the other root is X + U and
+        // conjugate: a_bar = (a_lo + U*a_hi) + a_hi*X
+        let u_times_a_hi = mul_by_imag_unit(&a_hi);
+        let conjugate_lo = add_limbs_helper(&a_lo, &u_times_a_hi);
+        let mut conjugate_elements = conjugate_lo;
+        conjugate_elements.extend(a_hi.clone());
+        let conjugate = Ring {
+            elements: conjugate_elements,
+        };
+
+        // norm = a * a_bar = a_lo^2 + a_hi^2 + a_lo*(U*a_hi), a subring
+        // (half-length) element: plugging `conjugate` into `recursive_mul`'s
+        // own low/high split makes the high half cancel, since
+        // `U*(a_hi*a_hi) == a_hi*(U*a_hi)` by associativity.
+        let a_lo_sq = recursive_mul(&a_lo, &a_lo);
+        let a_hi_sq = recursive_mul(&a_hi, &a_hi);
+        let a_lo_times_u_times_a_hi = recursive_mul(&a_lo, &u_times_a_hi);
+
+        let mut norm = add_limbs_helper(&a_lo_sq, &a_hi_sq);
+        norm = add_limbs_helper(&norm, &a_lo_times_u_times_a_hi);
+
+        let norm_inv = (Ring { elements: norm }).inverse()?;
+
+        Some(&conjugate * &norm_inv)
+    }
+
+    /// Same Montgomery's-trick batch inversion as
+    /// [`BinaryField::batch_inverse`](crate::binary_field::BinaryField::batch_inverse),
+    /// lifted to `Ring`: a single call to [`Ring::inverse`] plus `3*n`
+    /// multiplications rather than `n` calls to it. `values` must all share
+    /// the same length; a zero entry's output is left as zero.
+    pub fn batch_inverse(values: &[Self]) -> Vec<Self> {
+        if values.is_empty() {
+            return vec![];
+        }
+
+        let len = values[0].get_len();
+        let zero = Self::zero(len);
+
+        let mut prefix = Vec::with_capacity(values.len() + 1);
+        prefix.push(Self::one(len));
+        for v in values {
+            let last = prefix.last().unwrap();
+            let next = if *v == zero { last.clone() } else { last * v };
+            prefix.push(next);
+        }
+
+        let mut t = prefix[values.len()]
+            .inverse()
+            .expect("product of one and nonzero factors is never zero");
+
+        let mut result = vec![zero.clone(); values.len()];
+        for i in (0..values.len()).rev() {
+            if values[i] == zero {
+                continue;
+            }
+            result[i] = &t * &prefix[i];
+            t = &t * &values[i];
+        }
+        result
+    }
+}
+
+impl<F: BinaryFieldConfig> Div<&Ring<F>> for &Ring<F> {
+    type Output = Ring<F>;
+
+    // Division is multiplication by the inverse; this isn't a mixed-up operator.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: &Ring<F>) -> Self::Output {
+        self * &rhs.inverse().expect("division by zero")
+    }
+}
+
+impl<F: BinaryFieldConfig> DivAssign<&Ring<F>> for Ring<F> {
+    fn div_assign(&mut self, rhs: &Ring<F>) {
+        *self = (self as &Ring<F>) / rhs;
+    }
+}
+
 impl<F: BinaryFieldConfig> Ring<F> {
     pub fn from_bytes(l: usize, value: &[u8]) -> Self {
         let mut bits_le = vec![];
@@ -110,17 +210,33 @@ impl<F: BinaryFieldConfig> Ring<F> {
 
         let mut elements = vec![];
         for _ in 0..l {
-            let mut data = vec![];
+            let mut bits = vec![];
             for _ in 0..F::N {
-                data.push(*iter.next().unwrap());
+                bits.push(*iter.next().unwrap());
             }
-            elements.push(BinaryField::<F> {
-                data,
-                marker: PhantomData,
-            });
+            elements.push(BinaryField::<F>::from_bits(&bits));
         }
         Ring { elements }
     }
+
+    /// Inverse of [`Self::from_bytes`]: the canonical byte encoding of the
+    /// ring element, `len * N / 8` bytes long.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bits_le = vec![];
+        for element in &self.elements {
+            bits_le.extend(element.to_bits());
+        }
+        assert_eq!(bits_le.len() % 8, 0);
+
+        let mut bytes = vec![0u8; bits_le.len() / 8];
+        for (i, bit) in bits_le.iter().enumerate() {
+            if *bit {
+                bytes[i / 8] |= 1 << (i % 8);
+            }
+        }
+        bytes.reverse();
+        bytes
+    }
 }
 
 fn add_limbs_helper<F: BinaryFieldConfig>(
@@ -142,8 +258,8 @@ fn mul_by_imag_unit<F: BinaryFieldConfig>(a: &[BinaryField<F>]) -> Vec<BinaryFie
     assert!(len.is_power_of_two());
     assert!(len >= 2);
     if len == 2 {
-        let high = &a[0] + &a[1].mul_by_imag_unit();
-        let low = a[1].clone();
+        let high = a[0] + a[1].mul_by_imag_unit();
+        let low = a[1];
         vec![low, high]
     } else {
         let half_len = len / 2;
@@ -164,7 +280,7 @@ fn recursive_mul<F: BinaryFieldConfig>(
 ) -> Vec<BinaryField<F>> {
     assert_eq!(a.len(), b.len());
     if a.len() == 1 {
-        return vec![&a[0] * &b[0]];
+        return vec![a[0] * b[0]];
     }
 
     assert!(a.len().is_power_of_two());
@@ -178,8 +294,39 @@ fn recursive_mul<F: BinaryFieldConfig>(
     let a_sum = add_limbs_helper(&a_low, &a_high);
     let b_sum = add_limbs_helper(&b_low, &b_high);
 
+    // The three Karatsuba sub-products are independent, so above a size
+    // threshold we fork two of them onto the rayon pool instead of running
+    // all three serially.
+    #[cfg(feature = "parallel")]
+    const PARALLEL_THRESHOLD: usize = 16;
+
+    #[cfg(feature = "parallel")]
+    let (a_low_times_b_low, (a_high_times_b_high, a_sum_times_b_sum)) =
+        if a.len() >= PARALLEL_THRESHOLD {
+            rayon::join(
+                || recursive_mul(&a_low, &b_low),
+                || {
+                    rayon::join(
+                        || recursive_mul(&a_high, &b_high),
+                        || recursive_mul(&a_sum, &b_sum),
+                    )
+                },
+            )
+        } else {
+            (
+                recursive_mul(&a_low, &b_low),
+                (
+                    recursive_mul(&a_high, &b_high),
+                    recursive_mul(&a_sum, &b_sum),
+                ),
+            )
+        };
+
+    #[cfg(not(feature = "parallel"))]
     let a_low_times_b_low = recursive_mul(&a_low, &b_low);
+    #[cfg(not(feature = "parallel"))]
     let a_high_times_b_high = recursive_mul(&a_high, &b_high);
+    #[cfg(not(feature = "parallel"))]
     let a_sum_times_b_sum = recursive_mul(&a_sum, &b_sum);
 
     let mut mid_term = add_limbs_helper(&a_sum_times_b_sum, &a_low_times_b_low);
@@ -197,7 +344,7 @@ fn recursive_mul<F: BinaryFieldConfig>(
 
 #[cfg(test)]
 mod test {
-    use crate::binary_field::{AESPoly, F2};
+    use crate::binary_field::{AESPoly, BinaryField, F2};
     use crate::tower::Ring;
 
     #[test]
@@ -263,4 +410,52 @@ mod test {
         );
         assert_eq!(c, expected_c);
     }
+
+    #[test]
+    fn test_inverse() {
+        assert!(Ring::<F2>::from_bytes(4, &[0x00]).inverse().is_none());
+
+        for len in [1usize, 2, 4, 8] {
+            for byte in 1u8..=255 {
+                let a = Ring::<AESPoly>::from_bytes(len, &vec![byte; len]);
+                if let Some(a_inv) = a.inverse() {
+                    let one = {
+                        let mut elements = vec![BinaryField::<AESPoly>::zero(); len];
+                        elements[0] = BinaryField::<AESPoly>::one();
+                        Ring::<AESPoly> { elements }
+                    };
+                    assert_eq!(&a * &a_inv, one);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let len = 8;
+        let zero = Ring::<AESPoly>::zero(len);
+        let one = Ring::<AESPoly>::one(len);
+
+        let mut values: Vec<Ring<AESPoly>> = (1u8..=16)
+            .map(|b| Ring::<AESPoly>::from_bytes(len, &vec![b; len]))
+            .collect();
+        values[3] = zero.clone();
+
+        let inverses = Ring::<AESPoly>::batch_inverse(&values);
+
+        for (v, inv) in values.iter().zip(inverses.iter()) {
+            if *v == zero {
+                assert_eq!(*inv, zero);
+            } else {
+                assert_eq!(&(v * inv), &one);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        let bytes = [0xb7, 0x36, 0x28, 0x63];
+        let a = Ring::<AESPoly>::from_bytes(4, &bytes);
+        assert_eq!(a.to_bytes(), bytes);
+    }
 }